@@ -6,15 +6,23 @@ use std::rc::Rc;
 use std::sync::atomic;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread::JoinHandle;
 use std::time::Duration;
+use std::time::Instant;
 
 use pyo3::exceptions::PyTypeError;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use timely::communication::Allocate;
+use timely::dataflow::channels::pact::Exchange as ExchangePact;
+use timely::dataflow::channels::pact::Pipeline;
+use timely::dataflow::operators::generic::Operator;
 use timely::dataflow::operators::Broadcast;
 use timely::dataflow::operators::Concatenate;
+use timely::dataflow::operators::Inspect;
 use timely::dataflow::operators::Probe;
+use timely::dataflow::operators::ToStream;
 use timely::dataflow::ProbeHandle;
 use timely::dataflow::Scope;
 use timely::dataflow::Stream;
@@ -33,6 +41,48 @@ use crate::outputs::*;
 use crate::pyo3_extensions::TdPyAny;
 use crate::recovery::*;
 
+/// Tuning for the adaptive parking backoff in [`Worker::run`].
+///
+/// When the dataflow is making progress we want to stay hot and park
+/// for `floor` (near-zero) to keep latency low; when it goes idle we
+/// grow the park duration geometrically by `growth` up to `ceiling` so
+/// a quiescent worker stops spinning.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct BackoffConfig {
+    pub(crate) floor: Duration,
+    pub(crate) ceiling: Duration,
+    pub(crate) growth: u32,
+}
+
+impl Default for BackoffConfig {
+    fn default() -> Self {
+        Self {
+            floor: Duration::ZERO,
+            ceiling: Duration::from_millis(100),
+            growth: 2,
+        }
+    }
+}
+
+impl BackoffConfig {
+    /// The park duration for the next step given the current one and
+    /// whether the last step made progress.
+    fn next(&self, cur: Duration, made_progress: bool) -> Duration {
+        if made_progress {
+            return self.floor;
+        }
+        // Seed the geometric growth on the first idle step so we
+        // actually climb away from a zero (or tiny) floor.
+        let seed = self.floor.max(Duration::from_millis(1));
+        let grown = if cur < seed {
+            seed
+        } else {
+            cur * self.growth
+        };
+        grown.min(self.ceiling)
+    }
+}
+
 /// Bytewax worker.
 ///
 /// Wraps a [`TimelyWorker`].
@@ -46,6 +96,7 @@ where
     /// dataflow should perform an abrupt shutdown.
     interrupt_callback: F,
     abort: Arc<AtomicBool>,
+    backoff: BackoffConfig,
 }
 
 impl<'a, A, F> Worker<'a, A, F>
@@ -53,11 +104,12 @@ where
     A: Allocate,
     F: Fn() -> bool,
 {
-    fn new(worker: &'a mut TimelyWorker<A>, interrupt_callback: F) -> Self {
+    fn new(worker: &'a mut TimelyWorker<A>, interrupt_callback: F, backoff: BackoffConfig) -> Self {
         Self {
             worker,
             interrupt_callback,
             abort: Arc::new(AtomicBool::new(false)),
+            backoff,
         }
     }
 
@@ -69,14 +121,33 @@ where
         T: Timestamp,
     {
         tracing::info!("Timely dataflow start");
-        let cooldown = Duration::from_millis(1);
+        // Adaptive park: stay hot while the frontier is advancing,
+        // back off geometrically once the dataflow goes idle.
+        let mut park = self.backoff.floor;
+        let mut frontier: Vec<T> = Vec::new();
         while !(self.abort.load(atomic::Ordering::Relaxed)
             || (self.interrupt_callback)()
             || probe.done())
         {
+            let before = Instant::now();
             tracing::debug_span!("step").in_scope(|| {
-                self.worker.step_or_park(Some(cooldown));
+                self.worker.step_or_park(Some(park));
             });
+            // `step_or_park` blocks up to `park` but returns as soon as
+            // new work arrives. Waking well before the park elapsed means
+            // the worker was woken to do work, so count that as progress
+            // even when it did not advance the output frontier. Require a
+            // margin so timer granularity near the full park isn't
+            // mistaken for activity and busy-spun on.
+            let worked = park > Duration::ZERO && before.elapsed() < park.mul_f64(0.9);
+
+            // An advancing output frontier is also progress.
+            let mut observed: Vec<T> = Vec::new();
+            probe.with_frontier(|f| observed.extend(f.iter().cloned()));
+            let made_progress = worked || observed != frontier;
+            frontier = observed;
+
+            park = self.backoff.next(park, made_progress);
         }
         tracing::info!("Timely dataflow stop");
     }
@@ -94,6 +165,397 @@ where
     }
 }
 
+/// A single worker's ballot in the cluster-wide resume election.
+///
+/// Each worker contributes the last epoch it has durably backed up
+/// (`None` when it has no recovery data at all). The cluster resume
+/// epoch is the minimum across all votes so that no worker is ever
+/// asked to resume from an epoch it cannot reconstruct.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct ResumeVote {
+    worker_index: usize,
+    worker_count: usize,
+    last_durable_epoch: Option<u64>,
+    /// Set when the worker could not open its own recovery db. A
+    /// single failing db poisons the whole election and forces a full
+    /// replay from epoch 0.
+    db_error: bool,
+}
+
+/// Exchange one [`ResumeVote`] per worker and return every worker's
+/// ballot.
+///
+/// Timely builds dataflows symmetrically, so we cannot branch on a
+/// per-worker resume decision once `worker.dataflow(...)` starts. This
+/// runs a throwaway dataflow that broadcasts each worker's vote and
+/// drains it to completion before the production dataflow is built.
+fn broadcast_resume_votes<A>(worker: &mut TimelyWorker<A>, vote: ResumeVote) -> Vec<ResumeVote>
+where
+    A: Allocate,
+{
+    let votes = Rc::new(RefCell::new(Vec::new()));
+    let probe = {
+        let votes = votes.clone();
+        worker.dataflow::<u64, _, _>(|scope| {
+            let mut probe = ProbeHandle::new();
+            vec![vote]
+                .to_stream(scope)
+                .broadcast()
+                .inspect(move |vote: &ResumeVote| votes.borrow_mut().push(vote.clone()))
+                .probe_with(&mut probe);
+            probe
+        })
+    };
+    while !probe.done() {
+        worker.step();
+    }
+    worker.drop_dataflow(worker.installed_dataflows().pop().unwrap());
+    votes.take()
+}
+
+/// The cluster-wide resume decision reached by the vote election.
+///
+/// Kept as its own variant-bearing type rather than a bare
+/// [`ResumeEpoch`] so a trusted fast resume is distinguishable from a
+/// forced full replay. [`build_production_dataflow`] only needs the
+/// agreed epoch, which [`ClusterResume::resume_epoch`] projects out.
+#[derive(Clone, Copy, Debug)]
+enum ClusterResume {
+    /// Every fast-resume invariant held; resume from the agreed epoch
+    /// (the minimum last-durable epoch across the cluster).
+    FastResume(ResumeEpoch),
+    /// An invariant failed; every worker replays from epoch 0.
+    FullReplay,
+}
+
+impl ClusterResume {
+    /// The epoch every worker should rebuild from.
+    fn resume_epoch(self) -> ResumeEpoch {
+        match self {
+            ClusterResume::FastResume(epoch) => epoch,
+            ClusterResume::FullReplay => ResumeEpoch(0),
+        }
+    }
+}
+
+/// Decide the cluster resume from every worker's ballot.
+///
+/// Returns [`ClusterResume::FullReplay`] unless every fast-resume
+/// invariant holds: the number of ballots matches the worker count
+/// recorded at snapshot time, no worker signalled a db error, and
+/// every worker agrees on that count. Otherwise the agreed epoch is the
+/// minimum across ballots, where a worker with no durable data
+/// (`last_durable_epoch == None`) contributes epoch 0.
+fn decide_cluster_resume(votes: &[ResumeVote], snapshot_worker_count: usize) -> ClusterResume {
+    let healthy = votes.len() == snapshot_worker_count
+        && votes.iter().all(|v| !v.db_error)
+        && votes.iter().all(|v| v.worker_count == snapshot_worker_count);
+    if !healthy {
+        tracing::warn!(
+            observed = votes.len(),
+            expected = snapshot_worker_count,
+            "fast resume invariants failed; replaying from epoch 0"
+        );
+        return ClusterResume::FullReplay;
+    }
+    let epoch = votes
+        .iter()
+        .map(|v| v.last_durable_epoch.unwrap_or(0))
+        .min()
+        .unwrap_or(0);
+    ClusterResume::FastResume(ResumeEpoch(epoch))
+}
+
+/// zstd settings for durable recovery segments.
+///
+/// Mirrors the `compression`/`compression_level` fields on
+/// `RecoveryConfig`: with `enabled` false, segments are backed up
+/// uncompressed (the manifest still records their size and hash).
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Compression {
+    pub(crate) enabled: bool,
+    pub(crate) level: i32,
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        // zstd level 3 is the library default: a good size/speed balance
+        // for the state-heavy `stateful_batch` segments we back up.
+        Self {
+            enabled: true,
+            level: 3,
+        }
+    }
+}
+
+/// Sidecar metadata for a (possibly compressed) recovery segment.
+///
+/// Recorded alongside each artifact so the resume path can verify
+/// integrity and inflate back to the exact original bytes without
+/// having to trust the remote store.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub(crate) struct SegmentManifest {
+    /// Size in bytes of the original, uncompressed segment.
+    pub(crate) original_size: u64,
+    /// FNV-1a fingerprint of the original segment bytes.
+    pub(crate) content_hash: u64,
+    /// Whether the artifact bytes are zstd-compressed.
+    pub(crate) compressed: bool,
+}
+
+/// FNV-1a hash of `bytes`, the content fingerprint stored in a
+/// [`SegmentManifest`] and re-checked by the scrub loop.
+pub(crate) fn content_hash(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for &byte in bytes {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// Compress a recovery segment, returning the artifact bytes and a
+/// [`SegmentManifest`] describing the original. With compression
+/// disabled the segment passes through unchanged.
+pub(crate) fn compress_segment(
+    compression: Compression,
+    segment: &[u8],
+) -> std::io::Result<(Vec<u8>, SegmentManifest)> {
+    let manifest = SegmentManifest {
+        original_size: segment.len() as u64,
+        content_hash: content_hash(segment),
+        compressed: compression.enabled,
+    };
+    let artifact = if compression.enabled {
+        zstd::encode_all(segment, compression.level)?
+    } else {
+        segment.to_vec()
+    };
+    Ok((artifact, manifest))
+}
+
+/// Inflate an artifact produced by [`compress_segment`], verifying it
+/// against its [`SegmentManifest`]. Both the resume loader and the
+/// scrub loop go through here, so compressed and pass-through segments
+/// are handled identically.
+pub(crate) fn inflate_segment(manifest: &SegmentManifest, artifact: &[u8]) -> PyResult<Vec<u8>> {
+    let segment = if manifest.compressed {
+        zstd::decode_all(artifact).map_err(|err| {
+            tracked_err::<PyValueError>(&format!("failed to zstd-decode recovery segment: {err}"))
+        })?
+    } else {
+        artifact.to_vec()
+    };
+    if segment.len() as u64 != manifest.original_size
+        || content_hash(&segment) != manifest.content_hash
+    {
+        return Err(tracked_err::<PyValueError>(
+            "recovery segment failed its manifest integrity check",
+        ));
+    }
+    Ok(segment)
+}
+
+/// Default `INLINE_THRESHOLD`: snapshots smaller than this many bytes
+/// are stored inline rather than as their own segment. Mirrors the
+/// `inline_threshold` field on `RecoveryConfig`.
+pub(crate) const DEFAULT_INLINE_THRESHOLD: usize = 3 * 1024;
+
+/// How a compacted `stateful_batch` snapshot is stored.
+///
+/// Small payloads live inline in the compacted frontier db row; larger
+/// ones are written out as their own segment and backed up. The resume
+/// loader reads both through [`load_snapshot`], so callers upstream
+/// never have to distinguish them.
+pub(crate) enum StoredSnapshot {
+    /// Payload held inline in the compacted db (below threshold).
+    Inline(Vec<u8>),
+    /// Payload written to its own backed-up segment.
+    Segment(SegmentId),
+}
+
+/// Whether a serialized snapshot is small enough to inline.
+///
+/// Inlining avoids the fixed-cost segment-file + `durable_backup` path
+/// that otherwise dominates the many small per-key snapshots of a
+/// high-cardinality keyed dataflow.
+pub(crate) fn should_inline(payload_len: usize, inline_threshold: usize) -> bool {
+    payload_len < inline_threshold
+}
+
+/// Load a snapshot regardless of how it was stored.
+///
+/// Inline payloads are returned directly; segment-backed payloads are
+/// fetched from the backup store and inflated (and integrity-checked)
+/// via [`inflate_segment`], so the resume path handles both identically.
+pub(crate) fn load_snapshot(
+    backup: &Backup,
+    stored: &StoredSnapshot,
+    manifest: &SegmentManifest,
+) -> PyResult<Vec<u8>> {
+    match stored {
+        StoredSnapshot::Inline(bytes) => Ok(bytes.clone()),
+        StoredSnapshot::Segment(segment_id) => {
+            let artifact = backup.download(segment_id).map_err(|err| {
+                tracked_err::<PyValueError>(&format!("failed to download segment: {err}"))
+            })?;
+            inflate_segment(manifest, &artifact)
+        }
+    }
+}
+
+/// One durably-backed segment the scrub loop is responsible for.
+///
+/// Carries the [`SegmentManifest`] recorded at backup time, whose
+/// `content_hash` and `original_size` are the expected hash and size
+/// the loop re-checks on every pass.
+#[derive(Clone, Debug)]
+pub(crate) struct ScrubEntry {
+    pub(crate) segment_id: SegmentId,
+    /// Epoch the segment belongs to, used for frontier-based GC.
+    pub(crate) epoch: u64,
+    pub(crate) manifest: SegmentManifest,
+}
+
+/// Shared hand-off between the dataflow and the background scrub loop.
+///
+/// The recovery operators record each segment they back up and advance
+/// the durable cluster frontier here; the scrub loop reads this to know
+/// what to verify and what is old enough to garbage-collect.
+#[derive(Default)]
+pub(crate) struct ScrubQueue {
+    segments: HashMap<SegmentId, ScrubEntry>,
+    /// Segments strictly below this epoch can be reclaimed.
+    durable_frontier: u64,
+}
+
+impl ScrubQueue {
+    /// Register a freshly backed-up segment for future verification.
+    pub(crate) fn record_backup(&mut self, entry: ScrubEntry) {
+        self.segments.insert(entry.segment_id.clone(), entry);
+    }
+
+    /// Advance the durable cluster frontier.
+    pub(crate) fn advance_frontier(&mut self, epoch: u64) {
+        self.durable_frontier = self.durable_frontier.max(epoch);
+    }
+}
+
+/// Anti-entropy counters, logged once via [`tracing`] when
+/// [`ScrubHandle::join`] collects the stopped scrub loop.
+#[derive(Clone, Copy, Debug, Default)]
+struct ScrubStats {
+    scrubbed: u64,
+    repaired: u64,
+    deleted: u64,
+}
+
+/// Handle to the background scrub loop.
+pub(crate) struct ScrubHandle {
+    handle: JoinHandle<ScrubStats>,
+}
+
+impl ScrubHandle {
+    /// Wait for the scrub loop to wind down and log its final counters.
+    pub(crate) fn join(self) {
+        match self.handle.join() {
+            Ok(stats) => tracing::info!(
+                scrubbed = stats.scrubbed,
+                repaired = stats.repaired,
+                deleted = stats.deleted,
+                "scrub loop stopped"
+            ),
+            Err(_) => tracing::error!("scrub loop panicked"),
+        }
+    }
+}
+
+/// Spawn the background anti-entropy scrub loop.
+///
+/// Every `interval` it re-reads each segment this worker has backed up,
+/// re-inflates and re-hashes it via [`inflate_segment`], and repairs
+/// (re-uploads from the local temp copy) any that are missing or whose
+/// content no longer matches its manifest. Segments below the durable
+/// cluster frontier are garbage-collected from the backup store.
+/// Returns `None` when recovery is off, so there is nothing to scrub.
+pub(crate) fn spawn_scrub(
+    backup: Option<Backup>,
+    queue: Arc<Mutex<ScrubQueue>>,
+    interval: Duration,
+    abort: Arc<AtomicBool>,
+) -> Option<ScrubHandle> {
+    let backup = backup?;
+    let handle = std::thread::Builder::new()
+        .name("bytewax-scrub".into())
+        .spawn(move || scrub_loop(backup, &queue, interval, &abort))
+        .expect("failed to spawn scrub thread");
+    Some(ScrubHandle { handle })
+}
+
+#[instrument(name = "scrub", skip_all)]
+fn scrub_loop(
+    backup: Backup,
+    queue: &Arc<Mutex<ScrubQueue>>,
+    interval: Duration,
+    abort: &Arc<AtomicBool>,
+) -> ScrubStats {
+    let mut stats = ScrubStats::default();
+    while !abort.load(atomic::Ordering::Relaxed) {
+        // Snapshot the work under the lock, then release it so the
+        // dataflow can keep recording backups while we do remote IO.
+        let (entries, frontier) = {
+            let queue = queue.lock().unwrap();
+            (
+                queue.segments.values().cloned().collect::<Vec<_>>(),
+                queue.durable_frontier,
+            )
+        };
+
+        for entry in entries {
+            if abort.load(atomic::Ordering::Relaxed) {
+                break;
+            }
+
+            if entry.epoch < frontier {
+                // Below the durable cluster frontier: no worker can ask
+                // to resume this far back, so reclaim the space.
+                if backup.delete(&entry.segment_id).is_ok() {
+                    queue.lock().unwrap().segments.remove(&entry.segment_id);
+                    stats.deleted += 1;
+                    tracing::debug!(segment = ?entry.segment_id, "scrub gc");
+                }
+                continue;
+            }
+
+            stats.scrubbed += 1;
+            let intact = backup
+                .download(&entry.segment_id)
+                .ok()
+                .filter(|artifact| inflate_segment(&entry.manifest, artifact).is_ok())
+                .is_some();
+            if !intact {
+                // Missing or corrupt remote copy: re-upload from the
+                // local temp segment.
+                if backup.repair(&entry.segment_id).is_ok() {
+                    stats.repaired += 1;
+                    tracing::warn!(segment = ?entry.segment_id, "scrub repaired remote segment");
+                } else {
+                    tracing::error!(segment = ?entry.segment_id, "scrub could not repair segment");
+                }
+            }
+        }
+
+        // Park until the next interval, waking early on abort.
+        let tick = Duration::from_millis(100).min(interval).max(Duration::from_millis(1));
+        let mut waited = Duration::ZERO;
+        while waited < interval && !abort.load(atomic::Ordering::Relaxed) {
+            std::thread::sleep(tick);
+            waited += tick;
+        }
+    }
+    stats
+}
+
 /// Public, main entry point for a worker thread.
 #[instrument(name = "worker_main", skip_all, fields(worker = worker.index()))]
 pub(crate) fn worker_main<A>(
@@ -101,6 +563,7 @@ pub(crate) fn worker_main<A>(
     interrupt_callback: impl Fn() -> bool,
     flow: Dataflow,
     epoch_interval: EpochInterval,
+    backoff: BackoffConfig,
     recovery_config: Option<Py<RecoveryConfig>>,
 ) -> PyResult<()>
 where
@@ -108,7 +571,7 @@ where
 {
     let worker_index = worker.index();
     let worker_count = worker.peers();
-    let mut worker = Worker::new(worker, interrupt_callback);
+    let mut worker = Worker::new(worker, interrupt_callback, backoff);
     tracing::info!("Worker start");
 
     let recovery_config = recovery_config
@@ -117,24 +580,62 @@ where
 
     let flow_id = Python::with_gil(|py| flow.flow_id(py).unwrap());
 
-    // TODO: Now, initialize the StateStore object.
-    //       We need to decide if we can do a fast resume first.
-    //       We CAN'T do a fast resume if:
-    //       - The number of workers changed (state_store.worker_count vs current count)
-    //       - Any of the workers can't access its own db (corrupted? volume gone?)
-    //       If fast resume can be done, read the resume_from epoch from the state_store
-    //       and start from there.
-    let state = Rc::new(RefCell::new(StateStore::new(
-        recovery_config,
-        flow_id,
+    // Open the local recovery db. A worker that cannot open its own db
+    // must still cast a `db_error` ballot *before* bailing, otherwise
+    // the rest of the cluster never learns the db is broken and may
+    // trust an inconsistent fast resume. Timely builds dataflows
+    // symmetrically, so the throwaway vote exchange is the one place a
+    // failing worker can signal the cluster before it drops out.
+    let open = StateStore::new(recovery_config, flow_id, worker_index, worker_count);
+    let state = match open {
+        Ok(state) => Rc::new(RefCell::new(state)),
+        Err(err) => {
+            let vote = ResumeVote {
+                worker_index,
+                worker_count,
+                last_durable_epoch: None,
+                db_error: true,
+            };
+            broadcast_resume_votes(worker.worker, vote);
+            return Err(err).reraise("error opening recovery db");
+        }
+    };
+
+    // Decide the cluster resume epoch. Every worker broadcasts the
+    // last epoch it has durably backed up and the peer count it
+    // observes now; the agreed resume epoch is the minimum across
+    // the cluster so no worker is asked to resume from an epoch it
+    // cannot reconstruct. Fast resume is only trusted when the observed
+    // peer count matches the stored count and no worker hit a db error.
+    let (snapshot_worker_count, local_durable_epoch) = {
+        let state = state.borrow();
+        let ResumeFrom(_ex, ResumeEpoch(epoch)) = state.resume_from();
+        // Epoch 0 means nothing has been durably backed up beyond the
+        // start, i.e. no recovery data; vote `None` so the election
+        // treats it as "no data" rather than a genuine epoch-0 backup.
+        (state.worker_count(), (epoch != 0).then_some(epoch))
+    };
+    let vote = ResumeVote {
         worker_index,
         worker_count,
-    )?));
+        last_durable_epoch: local_durable_epoch,
+        db_error: false,
+    };
+    let votes = broadcast_resume_votes(worker.worker, vote);
+    let resume = decide_cluster_resume(&votes, snapshot_worker_count);
+    tracing::info!(?resume, "cluster resume decided");
 
-    // TODO: Only reading the latest epoch from the local db,
-    //       but we should also broadcast to all other workers
-    //       to get the cluster frontier.
-    let ResumeFrom(_ex, resume_epoch) = state.borrow().resume_from();
+    // Shared hand-off between the recovery pipeline (which records each
+    // segment it backs up and advances the durable frontier) and the
+    // background scrub loop below. Seed it with the segments already on
+    // the backup store from previous executions.
+    let scrub_queue = Arc::new(Mutex::new(ScrubQueue::default()));
+    {
+        let mut queue = scrub_queue.lock().unwrap();
+        for entry in state.borrow().backed_up_segments() {
+            queue.record_backup(entry);
+        }
+    }
 
     let probe = Python::with_gil(|py| {
         build_production_dataflow(
@@ -142,17 +643,38 @@ where
             worker.worker,
             flow,
             epoch_interval,
-            resume_epoch,
-            state,
+            resume,
+            state.clone(),
+            scrub_queue.clone(),
             &worker.abort,
         )
         .reraise("error building production dataflow")
     })?;
 
+    // Spawn the anti-entropy scrub loop. It periodically re-reads the
+    // segments this worker has backed up, repairs any that are missing
+    // or whose content hash no longer matches, and garbage-collects
+    // segments older than the durable cluster frontier. It shares the
+    // worker's `abort` flag so it winds down with the dataflow.
+    let scrub = spawn_scrub(
+        state.borrow().backup(),
+        scrub_queue,
+        state.borrow().scrub_interval(),
+        worker.abort.clone(),
+    );
+
     tracing::info_span!("production_dataflow").in_scope(|| {
         worker.run(probe);
     });
 
+    // `Worker::run` also returns on a clean end-of-stream shutdown,
+    // where `abort` was never set. Signal the scrub loop now so its
+    // join below returns instead of blocking on a thread that is still
+    // polling the backup store.
+    worker.abort.store(true, atomic::Ordering::Relaxed);
+    if let Some(scrub) = scrub {
+        scrub.join();
+    }
     worker.shutdown();
     tracing::info!("Worker stop");
     Ok(())
@@ -218,19 +740,176 @@ where
     }
 }
 
+/// A flat, region-allocated batch of pickled [`TdPyAny`] payloads.
+///
+/// Crossing an exchange boundary one [`TdPyAny`] at a time costs one
+/// pickle call and one heap allocation per element, which dominates the
+/// small-item, high-volume streams Bytewax targets. `PickledBatch`
+/// packs a whole batch contiguously instead: [`PickledBatch::push`]
+/// appends an item's pickle bytes to a single growable `arena` and
+/// records its `(offset, len)` in `index`, so a batch is pickled under
+/// one GIL acquisition and the receiving side reconstructs each
+/// [`TdPyAny`] by slicing the arena with no per-item allocation.
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+pub(crate) struct PickledBatch {
+    arena: Vec<u8>,
+    index: Vec<(usize, usize)>,
+}
+
+impl PickledBatch {
+    /// Append an already-pickled payload to the arena.
+    pub(crate) fn push(&mut self, pickled: &[u8]) {
+        let offset = self.arena.len();
+        self.arena.extend_from_slice(pickled);
+        self.index.push((offset, pickled.len()));
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Check that every index entry lies within the arena.
+    ///
+    /// A batch is built locally by [`PickledBatch::push`], so this only
+    /// ever fails on a payload corrupted in transit across the exchange;
+    /// the receiver calls it before [`PickledBatch::iter`] to turn
+    /// corruption into a recoverable error rather than a slice panic.
+    pub(crate) fn is_valid(&self) -> bool {
+        self.index
+            .iter()
+            .all(|&(offset, len)| offset.checked_add(len).is_some_and(|end| end <= self.arena.len()))
+    }
+
+    /// Iterate the packed payloads as byte slices.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.index
+            .iter()
+            .map(move |&(offset, len)| &self.arena[offset..offset + len])
+    }
+}
+
+/// A record crossing the `redistribute` exchange: either a whole
+/// batch packed into one [`PickledBatch`], or a single [`TdPyAny`]
+/// that could not be bulk-pickled and falls back to the per-item path.
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+enum Shuffled {
+    Packed(PickledBatch),
+    Raw(TdPyAny),
+}
+
+/// Pickle one Python object to bytes using a pre-resolved `pickle.dumps`.
+///
+/// `dumps` is looked up once per batch by the caller so packing a batch
+/// costs a single module import and attribute lookup, not one per item.
+fn pickle(dumps: &PyAny, obj: &TdPyAny) -> PyResult<Vec<u8>> {
+    let bytes = dumps.call1((obj.clone_ref(dumps.py()),))?;
+    Ok(bytes.extract::<&[u8]>()?.to_vec())
+}
+
+/// Reconstruct a Python object from pickle bytes using a pre-resolved
+/// `pickle.loads`.
+fn unpickle(loads: &PyAny, bytes: &[u8]) -> PyResult<TdPyAny> {
+    let obj = loads.call1((bytes,))?;
+    Ok(obj.into())
+}
+
+/// Rebalance `up` across workers, packing each batch into a single
+/// [`PickledBatch`] arena so the batch crosses the exchange as one
+/// record. The GIL is acquired and `pickle.dumps` resolved once per
+/// batch; each item still goes through its own `dumps` call. Items
+/// that fail to pickle are sent individually as
+/// [`Shuffled::Raw`], preserving the per-item fallback.
+fn redistribute_packed<S>(up: &Stream<S, TdPyAny>, step_id: StepId) -> Stream<S, TdPyAny>
+where
+    S: Scope,
+{
+    let pack_name = format!("{step_id:?}.redistribute_pack");
+    let unpack_name = format!("{step_id:?}.redistribute_unpack");
+
+    // Pack each incoming batch under one GIL acquisition, tagging it
+    // with a rotating route key so whole batches spread across peers.
+    // Seed the counter with this worker's index so peers don't all send
+    // their i-th batch to the same destination.
+    let start = up.scope().index() as u64;
+    let packed = up.unary(Pipeline, &pack_name, move |_cap, _info| {
+        let mut route: u64 = start;
+        move |input, output| {
+            input.for_each(|time, data| {
+                let mut session = output.session(&time);
+                Python::with_gil(|py| {
+                    let dumps = py.import("pickle").and_then(|m| m.getattr("dumps"));
+                    let mut batch = PickledBatch::default();
+                    let mut raws = Vec::new();
+                    for item in data.drain(..) {
+                        match dumps.as_ref().ok().and_then(|d| pickle(d, &item).ok()) {
+                            Some(bytes) => batch.push(&bytes),
+                            None => raws.push(item),
+                        }
+                    }
+                    if !batch.is_empty() {
+                        session.give((route, Shuffled::Packed(batch)));
+                    }
+                    for raw in raws {
+                        session.give((route, Shuffled::Raw(raw)));
+                    }
+                    route = route.wrapping_add(1);
+                });
+            });
+        }
+    });
+
+    // Shuffle by the route key, then unpack each batch back into
+    // individual `TdPyAny`s (again under a single GIL acquisition).
+    packed.unary(
+        ExchangePact::new(|(route, _): &(u64, Shuffled)| *route),
+        &unpack_name,
+        |_cap, _info| {
+            move |input, output| {
+                input.for_each(|time, data| {
+                    let mut session = output.session(&time);
+                    Python::with_gil(|py| {
+                        let loads = py.import("pickle").and_then(|m| m.getattr("loads"));
+                        for (_route, shuffled) in data.drain(..) {
+                            match shuffled {
+                                Shuffled::Packed(batch) if batch.is_valid() => {
+                                    for bytes in batch.iter() {
+                                        match loads.as_ref().map_err(|e| e.clone_ref(py)).and_then(|l| unpickle(l, bytes)) {
+                                            Ok(item) => session.give(item),
+                                            Err(err) => tracing::error!(
+                                                %err,
+                                                "dropping redistributed item that failed to unpickle"
+                                            ),
+                                        }
+                                    }
+                                }
+                                Shuffled::Packed(_) => {
+                                    tracing::error!("dropping corrupt redistributed batch");
+                                }
+                                Shuffled::Raw(item) => session.give(item),
+                            }
+                        }
+                    });
+                });
+            }
+        },
+    )
+}
+
 /// Turn a Bytewax dataflow into a Timely dataflow.
 fn build_production_dataflow<A>(
     py: Python,
     worker: &mut TimelyWorker<A>,
     flow: Dataflow,
     epoch_interval: EpochInterval,
-    resume_epoch: ResumeEpoch,
+    resume: ClusterResume,
     state_store: Rc<RefCell<StateStore>>,
+    scrub_queue: Arc<Mutex<ScrubQueue>>,
     abort: &Arc<AtomicBool>,
 ) -> PyResult<ProbeHandle<u64>>
 where
     A: Allocate,
 {
+    let resume_epoch = resume.resume_epoch();
     // Remember! Never build different numbers of Timely operators on
     // different workers! Timely does not like that and you'll see a
     // mysterious `failed to correctly cast channel` panic. You must
@@ -402,7 +1081,14 @@ where
                             .get_upstream(py, &step, "up")
                             .reraise("core operator `redistribute` missing port")?;
 
-                        let down = up.redistribute(step_id);
+                        // Pack each batch into a single [`PickledBatch`]
+                        // arena before the exchange so the batch crosses
+                        // as one record, with one GIL acquisition per
+                        // batch (each item is still pickled by its own
+                        // `dumps` call). Items that can't be bulk-pickled
+                        // fall back to the per-item path inside
+                        // `redistribute_packed`.
+                        let down = redistribute_packed(up, step_id);
 
                         streams
                             .insert_downstream(py, &step, "down", down)
@@ -454,16 +1140,40 @@ where
         // Attach the probe to the relevant final output.
         if recovery_on {
             let ssc = state_store.borrow();
+            // Only consult the compression config on the recovery path;
+            // the `RecoveryConfig` carries the on/off flag and the zstd
+            // level, and the `zstd_compress` operator below records the
+            // original size and content hash in each segment's manifest.
+            let compression = ssc.compression();
+            // Snapshots whose serialized payload is below this threshold
+            // are stored inline in the compacted db by `compact_snapshots`
+            // rather than emitted as standalone segments; the resume
+            // loader reads inline and file-backed snapshots identically.
+            let inline_threshold = ssc.inline_threshold();
             scope
                 // Concatenate all snapshot streams
                 .concatenate(snaps)
-                // Compact all of the snapshots of each worker
-                // into a temporary, local (to each worker) sqlite
-                // file, and emit a stream of paths for the files.
-                .compact_snapshots(state_store.clone())
+                // Compact all of the snapshots of each worker into a
+                // temporary, local (to each worker) sqlite file.
+                // Snapshots smaller than `inline_threshold` are stored
+                // inline in the compacted db and never leave as their
+                // own segment; only larger payloads are emitted as
+                // segment-file paths for the downstream backup path.
+                .compact_snapshots(state_store.clone(), inline_threshold)
+                // Stream each segment through a zstd encoder, emitting
+                // a `.zst` artifact plus a manifest recording the
+                // original size and content hash so the resume path can
+                // verify and inflate it.
+                .zstd_compress(compression)
                 // Now save each segment from all workers into
-                // a durable backup storate.
-                .durable_backup(ssc.backup().unwrap(), immediate_snapshot)
+                // a durable backup storate, recording each upload in
+                // the scrub queue so the anti-entropy loop can later
+                // re-verify it.
+                .durable_backup_tracked(
+                    ssc.backup().unwrap(),
+                    immediate_snapshot,
+                    scrub_queue.clone(),
+                )
                 // Now that the snapshot data is safe, we can
                 // update the cluster frontier.
                 // Broadcast the stream since we want all workers
@@ -472,10 +1182,15 @@ where
                 .broadcast()
                 // Write the frontier into a temp segment
                 .frontier_segment(state_store.clone())
+                // Compress the frontier segment the same way as the
+                // snapshot segments before uploading.
+                .zstd_compress(compression)
                 // Upload the segment to the durable backup
                 .durable_backup(ssc.backup().unwrap(), immediate_snapshot)
-                // And finally save the cluster frontier locally.
-                .compact_frontiers(state_store.clone())
+                // And finally save the cluster frontier locally, also
+                // advancing the scrub queue's durable frontier so old
+                // segments become eligible for garbage collection.
+                .compact_frontiers(state_store.clone(), scrub_queue.clone())
                 .probe_with(&mut probe);
         } else {
             scope.concatenate(outputs).probe_with(&mut probe);